@@ -1,5 +1,7 @@
 #[macro_use] extern crate matches;
 
+use std::fmt;
+use std::io::{self, BufRead};
 use std::ops::Deref;
 use std::str;
 
@@ -25,9 +27,9 @@ pub fn decode_step(input: &[u8]) -> (&str, DecodeStepStatus) {
             )
         };
         // ASCII characters are always valid, so only large
-        // bytes need more examination.
+        // bytes need more examination. Skip runs of ASCII a machine word at a time.
         if first < 128 {
-            position += 1
+            position = skip_ascii(input, position)
         } else {
             macro_rules! valid_prefix {
                 () => {
@@ -115,6 +117,57 @@ pub fn decode_step(input: &[u8]) -> (&str, DecodeStepStatus) {
     }
 }
 
+/// Table-driven UTF-8 decoding, using Björn Höhrmann's DFA formulation.
+///
+/// This is a drop-in alternative to `decode_step` with the same return shape: it trades the
+/// branch-heavy width table and continuation checks for two `static` lookups per byte, which
+/// tends to be faster on mixed input. Overlong and surrogate sequences transition to the
+/// REJECT state exactly like the RFC-3629 checks in `decode_step`, so the two agree on which
+/// byte sequences are well-formed.
+pub fn decode_step_dfa(input: &[u8]) -> (&str, DecodeStepStatus) {
+    // `state` is a byte offset into `UTF8_DFA_TRANSITIONS` (already multiplied by the number of
+    // classes), so the core step is `state = TRANS[state + CLASS[byte]]`.
+    let mut state = UTF8_DFA_ACCEPT;
+    // Index of the first byte of the sequence currently being decoded.
+    let mut sequence_start = 0;
+    let mut position = 0;
+    while position < input.len() {
+        let class = UTF8_DFA_CLASSES[input[position] as usize];
+        state = UTF8_DFA_TRANSITIONS[state as usize + class as usize];
+        if state == UTF8_DFA_REJECT {
+            // Match `decode_step`'s substitution-of-maximal-subparts offsets: a bad leading byte
+            // is skipped, a bad continuation is left for the next call to re-examine.
+            let remaining_start = if position == sequence_start {
+                position + 1
+            } else {
+                position
+            };
+            return (
+                unsafe { str::from_utf8_unchecked(&input[..sequence_start]) },
+                DecodeStepStatus::Error { remaining_input_after_error: &input[remaining_start..] },
+            )
+        }
+        position += 1;
+        if state == UTF8_DFA_ACCEPT {
+            sequence_start = position;
+        }
+    }
+    if state == UTF8_DFA_ACCEPT {
+        return (unsafe { str::from_utf8_unchecked(input) }, DecodeStepStatus::Ok)
+    }
+    // End of input in the middle of a so-far-valid sequence.
+    let tail = &input[sequence_start..];
+    (
+        unsafe { str::from_utf8_unchecked(&input[..sequence_start]) },
+        DecodeStepStatus::Incomplete(IncompleteSequence {
+            len: tail.len() as u8,
+            first: tail[0],
+            second: if tail.len() > 1 { tail[1] } else { 0 },
+            third: if tail.len() > 2 { tail[2] } else { 0 },
+        }),
+    )
+}
+
 #[must_use]
 #[derive(Debug)]
 pub enum DecodeStepStatus<'a> {
@@ -217,6 +270,51 @@ pub enum CompleteResult<'a> {
     StillIncomplete(IncompleteSequence),
 }
 
+/// Decode the last code point of a buffer whose end is a code-point boundary.
+///
+/// Unlike `decode_step`, which scans forward, this finds the start of the final code point by
+/// skipping back over the (at most three) trailing continuation bytes, then validates the
+/// leading byte and its continuations with the same rules as the forward path. It returns the
+/// final `StrChar` together with the bytes that precede it, so callers can iterate backwards or
+/// right-trim without re-scanning from the front.
+pub fn decode_last(input: &[u8]) -> DecodeLastResult {
+    if input.is_empty() {
+        return DecodeLastResult::Error { invalid_sequence: input }
+    }
+
+    // Step back over up to three continuation bytes to reach the leading byte.
+    let mut start = input.len() - 1;
+    let mut continuation_bytes = 0;
+    while start > 0 && continuation_bytes < 3 && is_continuation_byte(input[start]) {
+        start -= 1;
+        continuation_bytes += 1;
+    }
+
+    let sequence = &input[start..];
+    // The sequence is well-formed only if it validates and is consumed in full, i.e. the leading
+    // byte's width matches the number of trailing continuation bytes we found.
+    match decode_step(sequence) {
+        (prefix, DecodeStepStatus::Ok) if prefix.len() == sequence.len() => {
+            let mut bytes = [0; 4];
+            bytes[..sequence.len()].copy_from_slice(sequence);
+            DecodeLastResult::Ok {
+                code_point: StrChar { bytes: bytes },
+                remaining_input: &input[..start],
+            }
+        }
+        _ => DecodeLastResult::Error { invalid_sequence: sequence },
+    }
+}
+
+pub enum DecodeLastResult<'a> {
+    /// A well-formed final code point, and the bytes of `input` that precede it.
+    Ok { code_point: StrChar, remaining_input: &'a [u8] },
+
+    /// The trailing bytes are not a well-formed code point ending on a boundary.
+    /// Each such error should be represented as one U+FFFD replacement character in lossy decoding.
+    Error { invalid_sequence: &'a [u8] },
+}
+
 /// Like `char`, but represented in memory as UTF-8
 #[derive(Copy, Clone)]
 pub struct StrChar {
@@ -241,6 +339,340 @@ impl StrChar {
     pub fn to_char(&self) -> char {
         self.chars().next().unwrap()
     }
+
+    /// Like `to_char`, but compute the scalar value directly from the already-validated bytes
+    /// instead of re-running `str::chars`.
+    ///
+    /// This folds the leading byte (`byte & (0x7f >> width)`) with each continuation byte
+    /// (`acc = (acc << 6) | (byte & 0x3f)`), the standard UTF-8 accumulation, and is only
+    /// correct because a `StrChar` is always well-formed by construction.
+    #[inline]
+    pub fn to_char_unchecked(&self) -> char {
+        let width = width(self.bytes[0]) as usize;
+        let mut scalar = if width == 1 {
+            self.bytes[0] as u32
+        } else {
+            (self.bytes[0] & (0x7f >> width)) as u32
+        };
+        for i in 1..width {
+            scalar = (scalar << 6) | (self.bytes[i] & 0x3f) as u32;
+        }
+        unsafe {
+            std::char::from_u32_unchecked(scalar)
+        }
+    }
+}
+
+/// A push-based, lossy streaming UTF-8 decoder.
+///
+/// Bytes are fed in arbitrary chunks with `feed`, and the well-formed text of each chunk is
+/// handed to the sink closure as `&str` slices; a run that spans chunks, or a code point split
+/// across chunks, arrives in separate calls. Each decoding error is reported as exactly one
+/// `REPLACEMENT_CHARACTER`, with the same substitution semantics as `decode_step`, so the
+/// caller does not have to carry the cross-chunk `IncompleteSequence` state by hand.
+pub struct LossyDecoder<F: FnMut(&str)> {
+    push_str: F,
+    incomplete: Option<IncompleteSequence>,
+}
+
+impl<F: FnMut(&str)> LossyDecoder<F> {
+    /// Create a new decoder that calls `push_str` with each decoded string slice.
+    #[inline]
+    pub fn new(push_str: F) -> LossyDecoder<F> {
+        LossyDecoder { push_str: push_str, incomplete: None }
+    }
+
+    /// Feed one chunk of input, emitting decoded slices and replacement characters to the sink.
+    pub fn feed(&mut self, mut input: &[u8]) {
+        if let Some(incomplete) = self.incomplete.take() {
+            match incomplete.complete(input) {
+                CompleteResult::Ok { code_point, remaining_input } => {
+                    (self.push_str)(&code_point);
+                    input = remaining_input;
+                }
+                CompleteResult::Error { remaining_input_after_error } => {
+                    (self.push_str)(REPLACEMENT_CHARACTER);
+                    input = remaining_input_after_error;
+                }
+                CompleteResult::StillIncomplete(incomplete) => {
+                    self.incomplete = Some(incomplete);
+                    return
+                }
+            }
+        }
+        loop {
+            let (prefix, status) = decode_step(input);
+            if !prefix.is_empty() {
+                (self.push_str)(prefix);
+            }
+            match status {
+                DecodeStepStatus::Ok => return,
+                DecodeStepStatus::Error { remaining_input_after_error } => {
+                    (self.push_str)(REPLACEMENT_CHARACTER);
+                    input = remaining_input_after_error;
+                }
+                DecodeStepStatus::Incomplete(incomplete) => {
+                    self.incomplete = Some(incomplete);
+                    return
+                }
+            }
+        }
+    }
+
+    /// Flush a pending incomplete sequence, if any, as a single replacement character.
+    pub fn finish(self) {
+        let LossyDecoder { mut push_str, incomplete } = self;
+        if incomplete.is_some() {
+            push_str(REPLACEMENT_CHARACTER);
+        }
+    }
+}
+
+/// An error returned by `BufReadDecoder`.
+#[derive(Debug)]
+pub enum BufReadDecoderError<'a> {
+    /// Represents one or more (at most three) bytes that could not be decoded.
+    ///
+    /// The slice is the maximal invalid byte subsequence, mirroring the payload implied by
+    /// `DecodeStepStatus::Error` / `CompleteResult::Error`. In lossy decoding it maps to a
+    /// single `REPLACEMENT_CHARACTER`.
+    InvalidByteSequence(&'a [u8]),
+
+    /// An I/O error from the underlying reader.
+    Io(io::Error),
+}
+
+impl<'a> fmt::Display for BufReadDecoderError<'a> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BufReadDecoderError::InvalidByteSequence(bytes) => {
+                write!(formatter, "invalid byte sequence: {:?}", bytes)
+            }
+            BufReadDecoderError::Io(ref err) => err.fmt(formatter),
+        }
+    }
+}
+
+impl<'a> std::error::Error for BufReadDecoderError<'a> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            BufReadDecoderError::InvalidByteSequence(_) => None,
+            BufReadDecoderError::Io(ref err) => Some(err),
+        }
+    }
+}
+
+/// A pull-based adapter that decodes a `std::io::BufRead` into validated `&str` chunks.
+///
+/// Decoding is incremental: a small `IncompleteSequence` is carried across `fill_buf`/`consume`
+/// boundaries so arbitrarily large streams can be decoded without buffering the whole input. A
+/// sequence that is merely waiting for more bytes is only reported as an error once the reader
+/// reaches EOF.
+pub struct BufReadDecoder<R: BufRead> {
+    reader: R,
+    /// Bytes of the current `fill_buf` buffer to `consume` at the start of the next call.
+    /// Deferred so the reader is not advanced until the next call.
+    bytes_consumed: usize,
+    /// A sequence split across buffers, waiting for more input.
+    incomplete: Option<IncompleteSequence>,
+    /// Owned copy of the bytes returned by the current call. The returned `&str`/`&[u8]`
+    /// borrows this rather than the reader's `fill_buf` buffer, so the reader is free to be
+    /// re-borrowed on the next loop iteration (the borrow checker cannot prove a buffer borrow
+    /// is dead across iterations of `next_strict`).
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> BufReadDecoder<R> {
+    /// Create a decoder reading from `reader`.
+    #[inline]
+    pub fn new(reader: R) -> BufReadDecoder<R> {
+        BufReadDecoder {
+            reader: reader,
+            bytes_consumed: 0,
+            incomplete: None,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Decode and return the next chunk of well-formed UTF-8, an invalid-byte-sequence error,
+    /// or an I/O error. Returns `None` at the end of the input.
+    ///
+    /// Each returned `&str` is well-formed UTF-8 from at most one `fill_buf` buffer (a code point
+    /// split across buffers is returned on its own), so a long well-formed run may come back in
+    /// several pieces. An error is reported on its own, so well-formed text before an error byte
+    /// is yielded first.
+    pub fn next_strict(&mut self) -> Option<Result<&str, BufReadDecoderError>> {
+        loop {
+            if self.bytes_consumed > 0 {
+                self.reader.consume(self.bytes_consumed);
+                self.bytes_consumed = 0;
+            }
+
+            // First, try to finish a sequence that was split across a previous buffer.
+            if let Some(incomplete) = self.incomplete.take() {
+                let invalid = [incomplete.first, incomplete.second, incomplete.third, 0];
+                let invalid_len = incomplete.len as usize;
+                let buf = match self.reader.fill_buf() {
+                    Ok(buf) => buf,
+                    Err(error) => {
+                        self.incomplete = Some(incomplete);
+                        return Some(Err(BufReadDecoderError::Io(error)))
+                    }
+                };
+                if buf.is_empty() {
+                    // EOF in the middle of a sequence: now it is definitely an error.
+                    self.buf.clear();
+                    self.buf.extend_from_slice(&invalid[..invalid_len]);
+                    return Some(Err(BufReadDecoderError::InvalidByteSequence(&self.buf)))
+                }
+                match incomplete.complete(buf) {
+                    CompleteResult::Ok { code_point, remaining_input } => {
+                        self.bytes_consumed = buf.len() - remaining_input.len();
+                        self.buf.clear();
+                        self.buf.extend_from_slice(code_point.as_bytes());
+                        return Some(Ok(unsafe { str::from_utf8_unchecked(&self.buf) }))
+                    }
+                    CompleteResult::Error { remaining_input_after_error } => {
+                        let consumed = buf.len() - remaining_input_after_error.len();
+                        self.bytes_consumed = consumed;
+                        self.buf.clear();
+                        self.buf.extend_from_slice(&invalid[..invalid_len]);
+                        self.buf.extend_from_slice(&buf[..consumed]);
+                        return Some(Err(BufReadDecoderError::InvalidByteSequence(&self.buf)))
+                    }
+                    CompleteResult::StillIncomplete(incomplete) => {
+                        let consumed = buf.len();
+                        self.incomplete = Some(incomplete);
+                        self.reader.consume(consumed);
+                        continue
+                    }
+                }
+            }
+
+            let buf = match self.reader.fill_buf() {
+                Ok(buf) => buf,
+                Err(error) => return Some(Err(BufReadDecoderError::Io(error))),
+            };
+            if buf.is_empty() {
+                return None
+            }
+            let (prefix, status) = decode_step(buf);
+            match status {
+                DecodeStepStatus::Ok => {
+                    self.bytes_consumed = buf.len();
+                    self.buf.clear();
+                    self.buf.extend_from_slice(prefix.as_bytes());
+                    return Some(Ok(unsafe { str::from_utf8_unchecked(&self.buf) }))
+                }
+                DecodeStepStatus::Error { remaining_input_after_error } => {
+                    if !prefix.is_empty() {
+                        // Yield the valid prefix now; report the error on the next call.
+                        self.bytes_consumed = prefix.len();
+                        self.buf.clear();
+                        self.buf.extend_from_slice(prefix.as_bytes());
+                        return Some(Ok(unsafe { str::from_utf8_unchecked(&self.buf) }))
+                    }
+                    let invalid_len = buf.len() - remaining_input_after_error.len();
+                    self.bytes_consumed = invalid_len;
+                    self.buf.clear();
+                    self.buf.extend_from_slice(&buf[..invalid_len]);
+                    return Some(Err(BufReadDecoderError::InvalidByteSequence(&self.buf)))
+                }
+                DecodeStepStatus::Incomplete(incomplete) => {
+                    if !prefix.is_empty() {
+                        self.bytes_consumed = prefix.len();
+                        self.buf.clear();
+                        self.buf.extend_from_slice(prefix.as_bytes());
+                        return Some(Ok(unsafe { str::from_utf8_unchecked(&self.buf) }))
+                    }
+                    let consumed = buf.len();
+                    self.incomplete = Some(incomplete);
+                    self.reader.consume(consumed);
+                    continue
+                }
+            }
+        }
+    }
+
+    /// Like `next_strict`, but substitute a `REPLACEMENT_CHARACTER` for every invalid byte
+    /// sequence. Only I/O errors are surfaced to the caller.
+    pub fn next_lossy(&mut self) -> Option<Result<&str, io::Error>> {
+        self.next_strict().map(|result| match result {
+            Ok(decoded) => Ok(decoded),
+            Err(BufReadDecoderError::InvalidByteSequence(_)) => Ok(REPLACEMENT_CHARACTER),
+            Err(BufReadDecoderError::Io(error)) => Err(error),
+        })
+    }
+}
+
+/// A zero-copy iterator over a complete in-memory buffer, yielding each maximal well-formed
+/// UTF-8 run together with the invalid bytes that follow it.
+///
+/// This is the one-shot counterpart to `LossyDecoder`: it drives `decode_step` over a single
+/// `&[u8]` and hands the pieces back without a callback, so callers can do their own error
+/// reporting or lossy rendering.
+#[derive(Debug, Clone)]
+pub struct Utf8Chunks<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Utf8Chunks<'a> {
+    /// Create an iterator over `input`.
+    #[inline]
+    pub fn new(input: &'a [u8]) -> Utf8Chunks<'a> {
+        Utf8Chunks { input: input }
+    }
+}
+
+/// One step of `Utf8Chunks`.
+#[derive(Debug, Clone)]
+pub struct Utf8Chunk<'a> {
+    valid: &'a str,
+    invalid: &'a [u8],
+}
+
+impl<'a> Utf8Chunk<'a> {
+    /// The well-formed UTF-8 prefix of this chunk (possibly empty).
+    #[inline]
+    pub fn valid(&self) -> &'a str {
+        self.valid
+    }
+
+    /// The maximal invalid byte subsequence following `valid`, which should map to a single
+    /// U+FFFD. Empty on the final, well-formed chunk.
+    #[inline]
+    pub fn invalid(&self) -> &'a [u8] {
+        self.invalid
+    }
+}
+
+impl<'a> Iterator for Utf8Chunks<'a> {
+    type Item = Utf8Chunk<'a>;
+
+    fn next(&mut self) -> Option<Utf8Chunk<'a>> {
+        if self.input.is_empty() {
+            return None
+        }
+        let (prefix, status) = decode_step(self.input);
+        let invalid = match status {
+            DecodeStepStatus::Ok => {
+                self.input = &[];
+                &[]
+            }
+            DecodeStepStatus::Error { remaining_input_after_error } => {
+                let invalid = &self.input[prefix.len()..self.input.len() - remaining_input_after_error.len()];
+                self.input = remaining_input_after_error;
+                invalid
+            }
+            // End of input in the middle of a sequence: report the remainder as one invalid chunk.
+            DecodeStepStatus::Incomplete(_) => {
+                let invalid = &self.input[prefix.len()..];
+                self.input = &[];
+                invalid
+            }
+        };
+        Some(Utf8Chunk { valid: prefix, invalid: invalid })
+    }
 }
 
 #[inline]
@@ -268,6 +700,76 @@ static UTF8_CHAR_WIDTH: [u8; 256] = [
     4,4,4,4,4,0,0,0,0,0,0,0,0,0,0,0, // 0xFF
 ];
 
+// Björn Höhrmann's DFA, used by `decode_step_dfa`. ACCEPT is the start state; reaching it again
+// means a code point finished. REJECT is an absorbing error state.
+const UTF8_DFA_ACCEPT: u8 = 0;
+const UTF8_DFA_REJECT: u8 = 12;
+
+// Maps each byte to a character class. Continuation bytes are split into three ranges
+// (0x80-0x8F, 0x90-0x9F, 0xA0-0xBF) so the transition table can reject overlong and surrogate
+// sequences without any extra comparisons.
+static UTF8_DFA_CLASSES: [u8; 256] = [
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0, // 0x1F
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0, // 0x3F
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0, // 0x5F
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,
+    0,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0, // 0x7F
+    1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,1,
+    9,9,9,9,9,9,9,9,9,9,9,9,9,9,9,9, // 0x9F
+    7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,
+    7,7,7,7,7,7,7,7,7,7,7,7,7,7,7,7, // 0xBF
+    8,8,2,2,2,2,2,2,2,2,2,2,2,2,2,2,
+    2,2,2,2,2,2,2,2,2,2,2,2,2,2,2,2, // 0xDF
+    10,3,3,3,3,3,3,3,3,3,3,3,3,4,3,3, // 0xEF
+    11,6,6,6,5,8,8,8,8,8,8,8,8,8,8,8, // 0xFF
+];
+
+// Transition table indexed by `state + class`. Each row is one state (a multiple of the class
+// count); the value is the next state, or REJECT (12).
+static UTF8_DFA_TRANSITIONS: [u8; 108] = [
+    //  0   1   2   3   4   5   6   7   8   9  10  11  (class)
+     0, 12, 24, 36, 60, 96, 84, 12, 12, 12, 48, 72, // ACCEPT
+    12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, // REJECT
+    12,  0, 12, 12, 12, 12, 12,  0, 12,  0, 12, 12, // one trailing continuation
+    12, 24, 12, 12, 12, 12, 12, 24, 12, 24, 12, 12, // two trailing continuations
+    12, 12, 12, 12, 12, 12, 12, 24, 12, 12, 12, 12, // E0: second byte 0xA0-0xBF
+    12, 24, 12, 12, 12, 12, 12, 12, 12, 24, 12, 12, // ED: second byte 0x80-0x9F
+    12, 12, 12, 12, 12, 12, 12, 36, 12, 36, 12, 12, // F0: second byte 0x90-0xBF
+    12, 36, 12, 12, 12, 12, 12, 36, 12, 36, 12, 12, // F1-F3: second byte 0x80-0xBF
+    12, 36, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, // F4: second byte 0x80-0x8F
+];
+
+/// Advance `position` past a run of ASCII bytes in `input`, testing a whole machine word at a
+/// time and only falling back to a byte loop for the tail or once a non-ASCII byte is found.
+///
+/// `position` is assumed to be at a code-point boundary (the caller only calls this after seeing
+/// an ASCII byte), and the returned position is also at a boundary: it stops on the first byte
+/// with its high bit set, so the slow path still sees a complete leading byte. This uses only a
+/// plain `usize` load, so it compiles for the crate's SGX target without any intrinsics.
+#[inline]
+fn skip_ascii(input: &[u8], mut position: usize) -> usize {
+    // 0x8080…80: the high bit of every byte in a word. A word is all-ASCII iff none are set.
+    const HIGH_BITS: usize = (!0usize / 0xFF) << 7;
+    let word_size = std::mem::size_of::<usize>();
+
+    while position + word_size <= input.len() {
+        let mut bytes = [0u8; std::mem::size_of::<usize>()];
+        bytes.copy_from_slice(&input[position..position + word_size]);
+        if usize::from_ne_bytes(bytes) & HIGH_BITS != 0 {
+            break
+        }
+        position += word_size;
+    }
+    // Handle the trailing bytes, and any ASCII before the non-ASCII byte in the last word.
+    while position < input.len() && input[position] < 128 {
+        position += 1;
+    }
+    position
+}
+
 #[inline]
 fn is_continuation_byte(b: u8) -> bool {
     const CONTINUATION_MASK: u8 = 0b1100_0000;
@@ -295,3 +797,37 @@ fn valid_four_bytes_sequence_prefix(first: u8, second: u8) -> bool {
         (0xF4         , 0x80 ... 0x8F)
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    /// Drain a `BufReadDecoder` over `input`, rendering every invalid sequence as U+FFFD, and
+    /// assert the result matches `String::from_utf8_lossy`. The one-byte `BufReader` capacity
+    /// forces a `fill_buf` boundary between almost every byte, exercising the cross-buffer
+    /// `IncompleteSequence` carry that the borrow checker tripped over.
+    fn assert_lossy_parity(input: &[u8]) {
+        let mut decoder = BufReadDecoder::new(BufReader::with_capacity(1, input));
+        let mut decoded = String::new();
+        while let Some(result) = decoder.next_lossy() {
+            decoded.push_str(result.unwrap());
+        }
+        assert_eq!(decoded, String::from_utf8_lossy(input));
+    }
+
+    #[test]
+    fn bufread_decoder_matches_from_utf8_lossy() {
+        assert_lossy_parity(b"");
+        assert_lossy_parity(b"ascii only");
+        assert_lossy_parity("ĥéllö wörld — 𝄞 café".as_bytes());
+        // A valid multi-byte code point split across buffers by the one-byte capacity.
+        assert_lossy_parity("é".as_bytes());
+        // Lone continuation byte, truncated sequences, and overlong/surrogate errors.
+        assert_lossy_parity(b"a\x80b");
+        assert_lossy_parity(b"a\xe2\x82");
+        assert_lossy_parity(b"\xf0\x28\x8c\x28");
+        assert_lossy_parity(b"\xed\xa0\x80");
+        assert_lossy_parity(b"valid\xffthen\xc0more");
+    }
+}